@@ -1,5 +1,8 @@
 use smol::{channel, Timer};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 use std::error::Error;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 /// Represents data in an individual packet.
@@ -8,6 +11,32 @@ pub type MessageData = Vec<u8>;
 /// Measures bandwidth, in bytes / sec.
 pub type Bandwidth = u32;
 
+/// The outcome of attempting to send a packet along a bounded channel.
+///
+/// Senders without a configured buffer (see [`Sender::set_buffer`]) always
+/// produce [`SendOutcome::Sent`], since there's nothing to drop against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendOutcome {
+    /// The packet was accepted, and will be delivered after its transmission delay.
+    Sent,
+    /// The packet was dropped, because it would have overflowed the link's buffer.
+    Dropped,
+}
+
+/// The state of a link shared by every [`Sender`] cloned from the same origin.
+///
+/// Keeping this behind a shared lock is what lets several senders contend for
+/// a single bottleneck, the same way multiple producers share one mpsc channel.
+#[derive(Debug)]
+struct Link {
+    /// The bandwidth limiting our sending ability.
+    bandwidth: Option<Bandwidth>,
+    /// The next time the channel will be free to send data.
+    next_time: Instant,
+    /// The maximum backlog, in bytes, that we're willing to queue before dropping packets.
+    max_buffer: Option<usize>,
+}
+
 /// Represents a sender for the channel.
 ///
 /// This sender has a global bottleneck for everything being sent,
@@ -18,68 +47,289 @@ pub type Bandwidth = u32;
 /// In practice, there's also a delay here---which we do not model---but this setup
 /// is also equivalent to having an in library queue, by moving sending to a different
 /// thread, with an unbounded buffer in between.
+///
+/// Using [`channel_with_buffer`] instead gives the sender a finite buffer,
+/// modeling the drop-tail behavior of a real router queue: packets that
+/// would overflow the buffer are dropped instead of being queued forever.
+///
+/// `Sender` is [`Clone`]: every clone shares the same bottleneck, so cloning
+/// a sender models several hosts contending for one physical uplink, rather
+/// than giving each clone its own independent bandwidth budget.
 #[derive(Debug)]
 pub struct Sender<T> {
-    /// The bandwidth limiting our sending ability.
-    bandwidth: Option<Bandwidth>,
-    /// The next time the channel will be free to send data.
-    next_time: Instant,
+    link: Arc<Mutex<Link>>,
     chan: channel::Sender<(Instant, T)>,
 }
 
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Sender {
+            link: self.link.clone(),
+            chan: self.chan.clone(),
+        }
+    }
+}
+
 impl<T: 'static> Sender<T> {
     /// Send a message along this channel.
     ///
     /// All messages share the same bandwidth, and will be delayed accordingly.
+    /// This also holds across clones of this sender: they all serialize their
+    /// transmission delays against the same shared link.
+    ///
+    /// If this sender has a buffer configured (see [`Sender::set_buffer`]), and
+    /// the packet would overflow it, the packet is dropped instead of being sent,
+    /// and this is reflected in the returned [`SendOutcome`].
     ///
     /// This function will not block though.
-    pub async fn send(&mut self, size: usize, msg: T) -> Result<(), Box<dyn Error>> {
-        let transmission_delay = match self.bandwidth {
-            None => Duration::new(0, 0),
-            Some(bw) => Duration::from_secs_f64((size as f64) / (bw as f64)),
+    pub async fn send(&mut self, size: usize, msg: T) -> Result<SendOutcome, Box<dyn Error>> {
+        let departure_time = {
+            let mut link = self.link.lock().unwrap();
+            let transmission_delay = match link.bandwidth {
+                None => Duration::new(0, 0),
+                Some(bw) => Duration::from_secs_f64((size as f64) / (bw as f64)),
+            };
+            if let Some(max_buffer) = link.max_buffer {
+                let backlog = link.next_time.saturating_duration_since(Instant::now());
+                let backlog_bytes = match link.bandwidth {
+                    None => 0,
+                    Some(bw) => (backlog.as_secs_f64() * (bw as f64)) as usize,
+                };
+                if backlog_bytes.saturating_add(size) > max_buffer {
+                    return Ok(SendOutcome::Dropped);
+                }
+            }
+            // The packet leaves after the channel is free again, and we've
+            // managed to push all of the data making up the packet.
+            let departure_time = Instant::now().max(link.next_time) + transmission_delay;
+            link.next_time = departure_time;
+            departure_time
         };
-        // The packet leaves after the channel is free again, and we've
-        // managed to push all of the data making up the packet.
-        let departure_time = Instant::now().max(self.next_time) + transmission_delay;
         self.chan.send((departure_time, msg)).await?;
-        self.next_time = departure_time;
-        Ok(())
+        Ok(SendOutcome::Sent)
     }
 
     /// Set the bandwidth of this sender.
+    ///
+    /// This affects every clone of this sender, since they share the same link.
     pub fn set_bandwidth(&mut self, bandwidth: Bandwidth) {
-        self.bandwidth = Some(bandwidth);
+        self.link.lock().unwrap().bandwidth = Some(bandwidth);
+    }
+
+    /// Set the maximum backlog, in bytes, that this sender will queue before dropping packets.
+    ///
+    /// This models the finite buffer of a real router: once the projected backlog
+    /// plus the size of an incoming packet would exceed `max_bytes`, that packet
+    /// is dropped rather than queued.
+    ///
+    /// This affects every clone of this sender, since they share the same link.
+    pub fn set_buffer(&mut self, max_bytes: usize) {
+        self.link.lock().unwrap().max_buffer = Some(max_bytes);
     }
 }
 
+/// The per-receiver settings read by the delivery driver task (see
+/// [`run_receiver_driver`]) for every packet it processes.
+#[derive(Debug)]
+struct ReceiverConfig {
+    latency: Option<Duration>,
+    jitter: Option<(Duration, LatencyModel)>,
+    reorder: bool,
+}
+
 /// Represents a receiver for the channel.
 ///
 /// This receiver will be delayed because of the upstream bandwidth constraints,
 /// along with its individual latency constraints.
+///
+/// Internally, a background task reads packets off the upstream channel and
+/// re-delivers them, each behind its own independently timed delay; see
+/// [`Receiver::set_latency_jitter`] for why that matters.
 #[derive(Debug)]
 pub struct Receiver<T> {
-    latency: Option<Duration>,
-    chan: channel::Receiver<(Instant, T)>,
+    config: Arc<Mutex<ReceiverConfig>>,
+    chan: channel::Receiver<T>,
 }
 
 impl<T> Receiver<T> {
     /// Receive a message along the channel.
     ///
     /// This function can block if no message is ready, or if the message
-    /// is delayed because of the latency or bandwidth constraints of the channel.
+    /// is delayed because of the latency, jitter, or bandwidth constraints
+    /// of the channel. With jitter enabled, this may return messages out of
+    /// the order they were sent in; see [`Receiver::set_latency_jitter`].
     pub async fn recv(&self) -> Result<T, Box<dyn Error>> {
-        let (time, msg) = self.chan.recv().await?;
-        let time = match self.latency {
+        Ok(self.chan.recv().await?)
+    }
+
+    /// Set the latency of this receiver.
+    pub fn set_latency(&mut self, latency: Duration) {
+        self.config.lock().unwrap().latency = Some(latency);
+    }
+
+    /// Add stochastic jitter on top of a base latency, sampled per packet from
+    /// `distribution`.
+    ///
+    /// Sampling uses a fixed, seeded RNG, so simulations stay reproducible.
+    /// Each packet's delay is raced independently, so a packet that happens
+    /// to sample less jitter can genuinely overtake an earlier packet that's
+    /// still waiting: this naturally introduces packet reordering. Call
+    /// [`Receiver::set_reorder`] with `false` if FIFO delivery is needed
+    /// instead.
+    pub fn set_latency_jitter(&mut self, base: Duration, distribution: LatencyModel) {
+        self.config.lock().unwrap().jitter = Some((base, distribution));
+    }
+
+    /// Control whether jitter (see [`Receiver::set_latency_jitter`]) is allowed
+    /// to reorder packets.
+    ///
+    /// Defaults to `true`. Passing `false` clamps each packet's delivery time
+    /// to be at least as late as the previous one's, restoring FIFO delivery.
+    pub fn set_reorder(&mut self, reorder: bool) {
+        self.config.lock().unwrap().reorder = reorder;
+    }
+}
+
+/// A distribution for sampling per-packet jitter, used with
+/// [`Receiver::set_latency_jitter`].
+#[derive(Debug, Clone, Copy)]
+pub enum LatencyModel {
+    /// Jitter sampled uniformly from `[-spread, +spread]` around the base latency.
+    Uniform {
+        /// The maximum deviation, in either direction, from the base latency.
+        spread: Duration,
+    },
+    /// Jitter sampled from a normal distribution centered on the base latency.
+    Normal {
+        /// The standard deviation of the sampled jitter.
+        std_dev: Duration,
+    },
+}
+
+impl LatencyModel {
+    /// Samples a jitter delta, in nanoseconds, which may be negative.
+    fn sample(&self, rng: &mut Rng) -> i64 {
+        match self {
+            LatencyModel::Uniform { spread } => {
+                let spread_nanos = spread.as_nanos() as f64;
+                ((rng.next_f64() * 2.0 - 1.0) * spread_nanos) as i64
+            }
+            LatencyModel::Normal { std_dev } => {
+                let std_nanos = std_dev.as_nanos() as f64;
+                (rng.next_gaussian() * std_nanos) as i64
+            }
+        }
+    }
+}
+
+/// The fixed seed used to initialize every [`Receiver`]'s jitter RNG, so that
+/// simulations stay reproducible across runs.
+const DEFAULT_JITTER_SEED: u64 = 0x9E3779B97F4A7C15;
+
+/// A small seedable PRNG used to sample jitter reproducibly.
+///
+/// This is a xorshift64* generator: not cryptographically secure, but fast
+/// and deterministic given a seed, which is all that's needed for sampling
+/// simulated network jitter.
+#[derive(Debug)]
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // xorshift64* requires a non-zero state.
+        Rng { state: seed | 1 }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    /// Samples a uniform value in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Samples from a standard normal distribution, via the Box-Muller transform.
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = self.next_f64().max(f64::MIN_POSITIVE);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+/// Reads timestamped packets off `chan` in order, applies the receiver's
+/// latency and jitter settings, and hands each message to `out` once its
+/// delivery time comes due.
+///
+/// Each packet's delay is awaited in its own spawned task rather than
+/// serially, so packets race each other to `out`: a later packet that
+/// samples less jitter can be forwarded before an earlier one that's still
+/// waiting, which is what makes jitter actually reorder delivery instead of
+/// only shifting timing.
+async fn run_receiver_driver<T: Send + 'static>(
+    chan: channel::Receiver<(Instant, T)>,
+    config: Arc<Mutex<ReceiverConfig>>,
+    out: channel::Sender<T>,
+) {
+    let mut rng = Rng::new(DEFAULT_JITTER_SEED);
+    let mut last_deliver_at: Option<Instant> = None;
+    while let Ok((time, msg)) = chan.recv().await {
+        // The Receiver has been dropped: stop, so that `chan` closes and
+        // upstream senders can see that nobody is listening anymore.
+        if out.is_closed() {
+            return;
+        }
+        let (latency, jitter, reorder) = {
+            let config = config.lock().unwrap();
+            (config.latency, config.jitter, config.reorder)
+        };
+        let mut deliver_at = match latency {
             None => time,
             Some(l) => time + l,
         };
-        Timer::at(time).await;
-        Ok(msg)
+        if let Some((base, distribution)) = jitter {
+            let delta_nanos = distribution.sample(&mut rng);
+            deliver_at += if delta_nanos >= 0 {
+                base.saturating_add(Duration::from_nanos(delta_nanos as u64))
+            } else {
+                base.saturating_sub(Duration::from_nanos((-delta_nanos) as u64))
+            };
+        }
+        if !reorder {
+            if let Some(prev) = last_deliver_at {
+                deliver_at = deliver_at.max(prev);
+            }
+        }
+        last_deliver_at = Some(deliver_at);
+        let out = out.clone();
+        smol::spawn(async move {
+            Timer::at(deliver_at).await;
+            // Best effort: if the receiver has been dropped, there's nobody
+            // left to deliver to.
+            let _ = out.send(msg).await;
+        })
+        .detach();
     }
+}
 
-    /// Set the latency of this receiver.
-    pub fn set_latency(&mut self, latency: Duration) {
-        self.latency = Some(latency)
+/// Builds a [`Receiver`] with no latency or jitter configured yet, backed by
+/// a freshly spawned delivery driver task (see [`run_receiver_driver`]).
+fn new_receiver<T: Send + 'static>(chan: channel::Receiver<(Instant, T)>) -> Receiver<T> {
+    let config = Arc::new(Mutex::new(ReceiverConfig {
+        latency: None,
+        jitter: None,
+        reorder: true,
+    }));
+    let (out_sender, out_receiver) = channel::unbounded();
+    smol::spawn(run_receiver_driver(chan, config.clone(), out_sender)).detach();
+    Receiver {
+        config,
+        chan: out_receiver,
     }
 }
 
@@ -97,17 +347,296 @@ impl<T> Receiver<T> {
 ///
 /// These channels are also packet based, in the sense that senders transmit
 /// an entire packet
-pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+pub fn channel<T: Send + 'static>() -> (Sender<T>, Receiver<T>) {
     let (sender, receiver) = channel::unbounded();
+    let link = Link {
+        bandwidth: None,
+        next_time: Instant::now(),
+        max_buffer: None,
+    };
     (
         Sender {
-            bandwidth: None,
-            next_time: Instant::now(),
+            link: Arc::new(Mutex::new(link)),
             chan: sender,
         },
-        Receiver {
-            latency: None,
-            chan: receiver,
+        new_receiver(receiver),
+    )
+}
+
+/// Creates a delayed channel with a finite sending buffer.
+///
+/// This behaves exactly like [`channel`], except that the sender starts out
+/// with its buffer already set to `max_bytes` (see [`Sender::set_buffer`]).
+/// Packets that would push the projected backlog past `max_bytes` are dropped
+/// instead of being queued, modeling the drop-tail behavior of a router with
+/// a finite queue.
+pub fn channel_with_buffer<T: Send + 'static>(max_bytes: usize) -> (Sender<T>, Receiver<T>) {
+    let (mut sender, receiver) = channel();
+    sender.set_buffer(max_bytes);
+    (sender, receiver)
+}
+
+/// A packet waiting in a [`PrioritySender`]'s queue.
+///
+/// Ordered so that higher priority comes first, and among packets of equal
+/// priority, the one that arrived earliest comes first.
+struct QueuedPacket<T> {
+    priority: u8,
+    arrival: Instant,
+    size: usize,
+    msg: T,
+}
+
+impl<T> PartialEq for QueuedPacket<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.arrival == other.arrival
+    }
+}
+
+impl<T> Eq for QueuedPacket<T> {}
+
+impl<T> PartialOrd for QueuedPacket<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for QueuedPacket<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.arrival.cmp(&self.arrival))
+    }
+}
+
+/// The state shared between a [`PrioritySender`] and its background scheduler task.
+struct PriorityLink<T> {
+    bandwidth: Option<Bandwidth>,
+    next_time: Instant,
+    queue: BinaryHeap<QueuedPacket<T>>,
+}
+
+/// Represents a sender for a priority channel, created with [`priority_channel`].
+///
+/// Unlike [`Sender`], packets aren't scheduled in FIFO order as soon as they're
+/// sent. Instead, they're placed in a shared queue, and a background scheduler
+/// task forwards the best-ranked waiting packet every time the link becomes
+/// free, letting high priority traffic preempt lower priority traffic that's
+/// still queued. A packet already in transmission can't be preempted though:
+/// once the scheduler has claimed it, it must finish clocking its bytes out.
+#[derive(Clone)]
+pub struct PrioritySender<T> {
+    link: Arc<Mutex<PriorityLink<T>>>,
+    notify: channel::Sender<()>,
+}
+
+impl<T: Send + 'static> PrioritySender<T> {
+    /// Send a message along this channel, with the given priority.
+    ///
+    /// Higher priority values are forwarded first. Among packets of equal
+    /// priority, the one sent first is forwarded first. This function doesn't
+    /// block, since the packet is only queued here; the background scheduler
+    /// is the one that clocks it out onto the link.
+    pub async fn send(&mut self, priority: u8, size: usize, msg: T) {
+        {
+            let mut link = self.link.lock().unwrap();
+            link.queue.push(QueuedPacket {
+                priority,
+                arrival: Instant::now(),
+                size,
+                msg,
+            });
+        }
+        // Best effort: if the scheduler task has already shut down, there's
+        // nobody left to notify, and the packet just sits in the queue.
+        let _ = self.notify.send(()).await;
+    }
+
+    /// Set the bandwidth of this sender.
+    pub fn set_bandwidth(&mut self, bandwidth: Bandwidth) {
+        self.link.lock().unwrap().bandwidth = Some(bandwidth);
+    }
+}
+
+/// Runs the background scheduler for a priority channel.
+///
+/// Whenever the link is free and packets are waiting, this forwards the
+/// best-ranked one to `out`, in `(departure_time, msg)` form, exactly like a
+/// plain [`Sender`] would.
+async fn run_priority_scheduler<T: Send + 'static>(
+    link: Arc<Mutex<PriorityLink<T>>>,
+    notify: channel::Receiver<()>,
+    out: channel::Sender<(Instant, T)>,
+) {
+    loop {
+        let next_time = {
+            let link = link.lock().unwrap();
+            if link.queue.is_empty() {
+                None
+            } else {
+                Some(link.next_time)
+            }
+        };
+        let next_time = match next_time {
+            Some(next_time) => next_time,
+            // Nothing queued: wait for a sender to push something.
+            None => {
+                if notify.recv().await.is_err() {
+                    return;
+                }
+                continue;
+            }
+        };
+        // The link isn't free yet; wait for it, while still picking up any
+        // higher priority arrivals in the meantime.
+        let now = Instant::now();
+        if next_time > now {
+            Timer::at(next_time).await;
+        }
+        let packet = {
+            let mut link = link.lock().unwrap();
+            link.queue.pop()
+        };
+        let packet = match packet {
+            Some(packet) => packet,
+            None => continue,
+        };
+        let (departure_time, msg) = {
+            let mut link = link.lock().unwrap();
+            let transmission_delay = match link.bandwidth {
+                None => Duration::new(0, 0),
+                Some(bw) => Duration::from_secs_f64((packet.size as f64) / (bw as f64)),
+            };
+            let departure_time = Instant::now().max(link.next_time) + transmission_delay;
+            link.next_time = departure_time;
+            (departure_time, packet.msg)
+        };
+        if out.send((departure_time, msg)).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Creates a priority channel, for modeling QoS / DiffServ style scheduling.
+///
+/// The returned [`PrioritySender`] lets callers attach a `priority` to each
+/// packet; whenever the shared link becomes free, the highest priority packet
+/// still waiting is the one that gets to transmit, preempting lower priority
+/// traffic that arrived earlier. The returned [`Receiver`] behaves exactly
+/// like the one from [`channel`], with its own independent latency.
+pub fn priority_channel<T: Send + 'static>() -> (PrioritySender<T>, Receiver<T>) {
+    let (out_sender, out_receiver) = channel::unbounded();
+    let (notify_sender, notify_receiver) = channel::unbounded();
+    let link = Arc::new(Mutex::new(PriorityLink {
+        bandwidth: None,
+        next_time: Instant::now(),
+        queue: BinaryHeap::new(),
+    }));
+    smol::spawn(run_priority_scheduler(
+        link.clone(),
+        notify_receiver,
+        out_sender,
+    ))
+    .detach();
+    (
+        PrioritySender {
+            link,
+            notify: notify_sender,
         },
+        new_receiver(out_receiver),
     )
 }
+
+/// The state shared between a [`BroadcastSender`] and every [`Subscriber`] handed out for it.
+struct BroadcastLink<T> {
+    bandwidth: Option<Bandwidth>,
+    next_time: Instant,
+    subscribers: Vec<channel::Sender<(Instant, T)>>,
+}
+
+/// Represents a sender for a broadcast channel, created with [`broadcast_channel`].
+///
+/// Every message sent is cloned out to all current subscribers, but the
+/// transmission delay is only charged once against the shared link, since the
+/// frame is only transmitted once onto the broadcast medium.
+pub struct BroadcastSender<T> {
+    link: Arc<Mutex<BroadcastLink<T>>>,
+}
+
+impl<T: Clone + 'static> BroadcastSender<T> {
+    /// Send a message to every current subscriber of this channel.
+    ///
+    /// The transmission delay is computed once, from the bandwidth of this
+    /// sender, and applied as the `departure_time` seen by every subscriber;
+    /// each subscriber's [`Receiver`] then adds its own independent latency
+    /// on top, same as with a plain [`channel`].
+    ///
+    /// A broadcast medium has to tolerate stations leaving: a subscriber
+    /// whose [`Receiver`] has been dropped doesn't stop delivery to the
+    /// others, and is pruned from the subscriber list instead.
+    pub async fn send(&mut self, size: usize, msg: T) -> Result<(), Box<dyn Error>> {
+        let (departure_time, subscribers) = {
+            let mut link = self.link.lock().unwrap();
+            let transmission_delay = match link.bandwidth {
+                None => Duration::new(0, 0),
+                Some(bw) => Duration::from_secs_f64((size as f64) / (bw as f64)),
+            };
+            let departure_time = Instant::now().max(link.next_time) + transmission_delay;
+            link.next_time = departure_time;
+            (departure_time, link.subscribers.clone())
+        };
+        for subscriber in &subscribers {
+            // Best effort: a closed subscriber shouldn't sink delivery to
+            // the rest of the broadcast group.
+            let _ = subscriber.send((departure_time, msg.clone())).await;
+        }
+        self.link
+            .lock()
+            .unwrap()
+            .subscribers
+            .retain(|s| !s.is_closed());
+        Ok(())
+    }
+
+    /// Set the bandwidth of this sender.
+    pub fn set_bandwidth(&mut self, bandwidth: Bandwidth) {
+        self.link.lock().unwrap().bandwidth = Some(bandwidth);
+    }
+}
+
+/// Hands out [`Receiver`]s for a broadcast channel, created with [`broadcast_channel`].
+///
+/// Each subscriber added through [`Subscriber::subscribe`] gets its own
+/// [`Receiver`], with its own independent latency, but all of them are fed by
+/// the same shared link.
+pub struct Subscriber<T> {
+    link: Arc<Mutex<BroadcastLink<T>>>,
+}
+
+impl<T: Send + 'static> Subscriber<T> {
+    /// Subscribe a new receiver to this broadcast channel.
+    ///
+    /// Every message sent after this call will also be delivered to the
+    /// returned [`Receiver`], which starts out with no latency, same as the
+    /// receiver from [`channel`].
+    pub fn subscribe(&self) -> Receiver<T> {
+        let (sender, receiver) = channel::unbounded();
+        self.link.lock().unwrap().subscribers.push(sender);
+        new_receiver(receiver)
+    }
+}
+
+/// Creates a broadcast channel, modeling one shared link feeding many receivers.
+///
+/// This is meant for shared-medium broadcast, like a hub or a wireless
+/// segment, rather than independent point-to-point links: a single
+/// [`BroadcastSender::send`] call reaches every [`Receiver`] handed out by the
+/// [`Subscriber`], but only pays the transmission delay once.
+pub fn broadcast_channel<T: Clone + Send + 'static>() -> (BroadcastSender<T>, Subscriber<T>) {
+    let link = Arc::new(Mutex::new(BroadcastLink {
+        bandwidth: None,
+        next_time: Instant::now(),
+        subscribers: Vec::new(),
+    }));
+    (BroadcastSender { link: link.clone() }, Subscriber { link })
+}